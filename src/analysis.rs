@@ -0,0 +1,157 @@
+use crate::history::{self, Observation};
+use anyhow::Result;
+use chrono::{Datelike, Timelike, Weekday};
+use std::{collections::HashMap, io::Write};
+use tabwriter::TabWriter;
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// How many ranked buckets to report per location.
+const TOP_BUCKETS: usize = 3;
+
+/// Buckets recorded history by weekday/hour-of-day and reports, per location,
+/// the times new slots have most frequently appeared.
+pub fn run() -> Result<()> {
+    let observations = history::read_all()?;
+
+    let mut by_location: HashMap<(String, String), Vec<Observation>> = HashMap::new();
+    for observation in observations {
+        by_location
+            .entry((observation.municipality.clone(), observation.organization.clone()))
+            .or_default()
+            .push(observation);
+    }
+
+    let mut tabwriter = TabWriter::new(std::io::stdout());
+
+    let mut locations = by_location.into_iter().collect::<Vec<_>>();
+    locations.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for ((municipality, organization), mut observations) in locations {
+        observations.sort_by_key(|observation| observation.timestamp);
+
+        let buckets = transition_buckets(&observations);
+
+        if buckets.is_empty() {
+            continue;
+        }
+
+        let (best_weekday, best_hour, _) = buckets[0];
+
+        let top_buckets = buckets
+            .iter()
+            .take(TOP_BUCKETS)
+            .map(|(weekday, hour, count)| format_bucket(*weekday, *hour, *count))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        writeln!(
+            tabwriter,
+            "{}\t{}\tmost likely: {} {:02}:00\ttop buckets: {}",
+            municipality, organization, WEEKDAYS[best_weekday], best_hour, top_buckets,
+        )?;
+    }
+
+    tabwriter.flush()?;
+
+    Ok(())
+}
+
+fn format_bucket(weekday: usize, hour: usize, count: u32) -> String {
+    format!(
+        "{} {:02}:00 ({} release{})",
+        WEEKDAYS[weekday],
+        hour,
+        count,
+        if count == 1 { "" } else { "s" },
+    )
+}
+
+/// Walks chronologically-sorted observations and records the weekday/hour of
+/// every "transition to available" event (`num_available` rising from the
+/// previous sample) into a 7×24 matrix, returned as `(weekday, hour, count)`
+/// buckets ranked highest-count first.
+fn transition_buckets(observations: &[Observation]) -> Vec<(usize, usize, u32)> {
+    let mut matrix = [[0u32; 24]; 7];
+    let mut previous_num_available = None;
+
+    for observation in observations {
+        if let Some(previous) = previous_num_available {
+            if observation.num_available > previous {
+                let weekday = observation.timestamp.weekday().num_days_from_monday() as usize;
+                let hour = observation.timestamp.hour() as usize;
+                matrix[weekday][hour] += 1;
+            }
+        }
+        previous_num_available = Some(observation.num_available);
+    }
+
+    let mut buckets = matrix
+        .iter()
+        .enumerate()
+        .flat_map(|(weekday, hours)| {
+            hours
+                .iter()
+                .enumerate()
+                .map(move |(hour, count)| (weekday, hour, *count))
+        })
+        .filter(|(_, _, count)| *count > 0)
+        .collect::<Vec<_>>();
+
+    buckets.sort_by_key(|(_, _, count)| std::cmp::Reverse(*count));
+
+    buckets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn observation(municipality: &str, num_available: u64, y: i32, m: u32, d: u32, h: u32) -> Observation {
+        Observation {
+            municipality: municipality.to_owned(),
+            organization: "Test Clinic".to_owned(),
+            num_available,
+            timestamp: chrono::Local.with_ymd_and_hms(y, m, d, h, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn ranks_buckets_by_transition_count() {
+        let observations = vec![
+            // Monday 08:00, two weeks in a row: 0 -> 3 is a transition.
+            observation("Ale", 0, 2024, 1, 1, 8),
+            observation("Ale", 3, 2024, 1, 1, 8),
+            observation("Ale", 0, 2024, 1, 8, 8),
+            observation("Ale", 2, 2024, 1, 8, 8),
+            // Wednesday 14:00, only one transition.
+            observation("Ale", 0, 2024, 1, 3, 14),
+            observation("Ale", 1, 2024, 1, 3, 14),
+        ];
+
+        let buckets = transition_buckets(&observations);
+
+        assert_eq!(buckets[0], (0, 8, 2));
+        assert_eq!(buckets[1], (2, 14, 1));
+    }
+
+    #[test]
+    fn ignores_drops_and_flat_samples() {
+        let observations = vec![
+            observation("Ale", 3, 2024, 1, 1, 8),
+            observation("Ale", 3, 2024, 1, 1, 9),
+            observation("Ale", 0, 2024, 1, 1, 10),
+        ];
+
+        assert!(transition_buckets(&observations).is_empty());
+    }
+}
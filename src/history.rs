@@ -0,0 +1,71 @@
+use crate::location::Location;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::OpenOptions,
+    io::{BufRead, BufReader, Write},
+    path::PathBuf,
+};
+
+/// A single `(municipality, organization, num_available, timestamp)` observation,
+/// appended to the history log on every poll.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Observation {
+    pub municipality: String,
+    pub organization: String,
+    pub num_available: u64,
+    pub timestamp: DateTime<Local>,
+}
+
+impl Observation {
+    fn from_location(location: &Location, timestamp: DateTime<Local>) -> Self {
+        Observation {
+            municipality: location.municipality.clone(),
+            organization: location.organization.clone(),
+            num_available: location.num_available,
+            timestamp,
+        }
+    }
+}
+
+/// Appends one observation per location to the history log.
+pub fn record(locations: &[Location], timestamp: DateTime<Local>) -> Result<()> {
+    let path = history_path()?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    for location in locations {
+        let observation = Observation::from_location(location, timestamp);
+        serde_json::to_writer(&file, &observation).map_err(|err| anyhow!(err))?;
+        file.write_all(b"\n")?;
+    }
+
+    Ok(())
+}
+
+/// Reads back every recorded observation, in the order they were appended.
+pub fn read_all() -> Result<Vec<Observation>> {
+    let path = history_path()?;
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let file = std::fs::File::open(path)?;
+    BufReader::new(file)
+        .lines()
+        .map(|line| {
+            let line = line?;
+            serde_json::from_str(&line).map_err(|err| anyhow!(err))
+        })
+        .collect()
+}
+
+fn history_path() -> Result<PathBuf> {
+    let dirs = directories::ProjectDirs::from("", "", "gibvaccine")
+        .context("could not determine a config directory for this platform")?;
+    Ok(dirs.data_dir().join("history.jsonl"))
+}
@@ -0,0 +1,26 @@
+use serde::Serialize;
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Location {
+    pub municipality: String,
+    pub organization: String,
+    pub booking_link: String,
+    pub num_available: u64,
+}
+
+impl Location {
+    pub fn key(&self) -> (String, String) {
+        (self.municipality.clone(), self.organization.clone())
+    }
+
+    /// Whether this location's municipality passes `filter`, a comma-separated
+    /// list of municipality names (case-insensitive, exact match per entry).
+    /// An empty filter matches everything.
+    pub fn matches_filter(&self, filter: &str) -> bool {
+        filter.is_empty()
+            || filter
+                .split(',')
+                .map(str::trim)
+                .any(|municipality| municipality.eq_ignore_ascii_case(&self.municipality))
+    }
+}
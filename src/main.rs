@@ -1,38 +1,150 @@
+mod analysis;
+mod history;
+mod location;
+mod notifier;
+mod output;
+mod provider;
+mod providers;
+mod session;
+mod tui;
+
 use anyhow::Result;
+use clap::{Parser, Subcommand, ValueEnum};
+use location::Location;
+use notifier::{BrowserNotifier, DesktopNotifier, Dispatcher, Notifier, WebhookNotifier};
+use provider::Provider;
 use rand::Rng;
-use regex::Regex;
-use scraper::{Html, Selector};
-use std::{collections::HashMap, io::Write, time::Duration};
+use session::Session;
+use std::{
+    collections::HashMap,
+    io::Write,
+    sync::mpsc::{self, Sender},
+    time::Duration,
+};
 use tabwriter::TabWriter;
 use termion::{color, style};
+use tui::Update;
 
-const URL: &str = "https://www.vgregion.se/ov/vaccinationstider/bokningsbara-tider/";
 const MUNICIPALITIES: &[&str] = &["Ale", "Göteborg", "Kungälv", "Mölndal"];
 const MIN_SLEEP_DURATION: Duration = Duration::from_secs(50);
 const MAX_SLEEP_DURATION: Duration = Duration::from_secs(120);
 
-#[derive(Clone, Debug)]
-struct Location {
-    municipality: String,
-    organization: String,
-    booking_link: String,
-    num_available: u64,
+/// Watches booking sites for newly available vaccination slots.
+#[derive(Parser)]
+struct Args {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
+    /// Only poll the provider whose base URL matches this (substring match).
+    #[clap(long, global = true)]
+    provider: Option<String>,
+
+    /// User-Agent header sent with every request.
+    #[clap(long, global = true)]
+    user_agent: Option<String>,
+
+    /// Open a full-screen terminal UI instead of printing a scrolling table.
+    #[clap(long)]
+    tui: bool,
+
+    /// Output format for the watch loop.
+    #[clap(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Notification backends to fire for changed locations, e.g. `--notify
+    /// browser,desktop`. Defaults to opening the browser, as before.
+    #[clap(long, value_enum, value_delimiter = ',')]
+    notify: Vec<NotifierKind>,
+
+    /// Webhook URL to POST changed locations to. Required when `--notify` includes `webhook`.
+    #[clap(long)]
+    webhook_url: Option<String>,
+
+    /// Only notify for locations with at least this many available slots.
+    #[clap(long, default_value_t = 1)]
+    min_available: u64,
 }
 
-impl Location {
-    fn key(&self) -> (String, String) {
-        (self.municipality.clone(), self.organization.clone())
-    }
+#[derive(Subcommand)]
+enum Command {
+    /// Report, per location, when new slots have historically appeared.
+    Analyze,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// The colored, human-readable table (default).
+    Text,
+    /// One JSON record per poll iteration, for `jq`/webhooks/dashboards.
+    Json,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum NotifierKind {
+    Browser,
+    Desktop,
+    Webhook,
 }
 
 fn main() -> Result<()> {
+    let args = Args::parse();
+
+    if matches!(args.command, Some(Command::Analyze)) {
+        return analysis::run();
+    }
+
+    let providers: Vec<Box<dyn Provider>> = providers::all()
+        .into_iter()
+        .filter(|provider| match &args.provider {
+            Some(url) => provider.matches(url),
+            None => true,
+        })
+        .collect();
+
+    let mut session = Session::new(args.user_agent.as_deref())?;
+
+    for provider in &providers {
+        provider.login(&mut session)?;
+    }
+
+    if args.tui {
+        let (tx, rx) = mpsc::channel();
+        std::thread::spawn(move || poll_loop(&providers, &session, tx));
+        tui::run(rx)?;
+        return Ok(());
+    }
+
+    let default_filter = MUNICIPALITIES.join(",");
+    let mut dispatcher = build_dispatcher(&args)?;
+
     let mut is_first_run = true;
-    let mut current_locations: HashMap<(String, String), Location> = HashMap::new();
-    let mut rng = rand::thread_rng();
 
-    loop {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || poll_loop(&providers, &session, tx));
+
+    for update in rx {
         let now = chrono::Local::now();
 
+        let filtered_locations = update
+            .changed
+            .iter()
+            .filter(|location| location.matches_filter(&default_filter))
+            .collect::<Vec<_>>();
+
+        if !is_first_run {
+            let changed = filtered_locations
+                .iter()
+                .map(|location| (*location).clone())
+                .collect::<Vec<_>>();
+            dispatcher.dispatch(&changed);
+        }
+
+        if args.format == Format::Json {
+            output::print_json(&update.locations, &update.changed, now)?;
+            is_first_run = false;
+            continue;
+        }
+
         println!(
             "{}{}{} {}{}{}",
             color::Fg(color::LightBlack),
@@ -43,34 +155,8 @@ fn main() -> Result<()> {
             style::Reset,
         );
 
-        let locations = get_available()?;
-
-        let mut changed_locations = vec![];
-
-        for location in locations.iter() {
-            current_locations
-                .entry(location.key())
-                .and_modify(|mut old_location| {
-                    if old_location.num_available != location.num_available {
-                        changed_locations.push(location.clone());
-                    }
-                    old_location.num_available = location.num_available;
-                })
-                .or_insert_with(|| {
-                    changed_locations.push(location.clone());
-                    location.clone()
-                });
-        }
-
-        changed_locations.sort_by_key(|location| location.num_available);
-
-        let filtered_locations = changed_locations
-            .iter()
-            .filter(|location| MUNICIPALITIES.contains(&location.municipality.as_str()))
-            .collect::<Vec<_>>();
-
-        if changed_locations.len() > filtered_locations.len() {
-            let num_filtered = changed_locations.len() - filtered_locations.len();
+        if update.changed.len() > filtered_locations.len() {
+            let num_filtered = update.changed.len() - filtered_locations.len();
             if num_filtered == 1 {
                 println!("Filtered 1 location.");
             } else {
@@ -97,96 +183,100 @@ fn main() -> Result<()> {
 
         tabwriter.flush()?;
 
-        if let Some(location) = filtered_locations.first() {
-            if !is_first_run {
-                open::that(&location.booking_link)?;
-            }
-        }
-
         is_first_run = false;
+    }
 
-        let sleep_duration = rng.gen_range(MIN_SLEEP_DURATION..MAX_SLEEP_DURATION);
-        std::thread::sleep(sleep_duration);
+    Ok(())
+}
+
+/// Builds the notifier stack from `--notify`, defaulting to a lone
+/// `BrowserNotifier` if the flag is left off. Unlike before, every changed
+/// location that passes the filter and threshold is notified, not just the
+/// one with the fewest slots.
+fn build_dispatcher(args: &Args) -> Result<Dispatcher> {
+    let kinds = if args.notify.is_empty() {
+        vec![NotifierKind::Browser]
+    } else {
+        args.notify.clone()
+    };
+
+    let mut notifiers: Vec<Box<dyn Notifier>> = vec![];
+
+    for kind in kinds {
+        let notifier: Box<dyn Notifier> = match kind {
+            NotifierKind::Browser => Box::new(BrowserNotifier),
+            NotifierKind::Desktop => Box::new(DesktopNotifier),
+            NotifierKind::Webhook => {
+                let url = args
+                    .webhook_url
+                    .clone()
+                    .ok_or_else(|| anyhow::anyhow!("--notify webhook requires --webhook-url"))?;
+                Box::new(WebhookNotifier::new(url))
+            }
+        };
+        notifiers.push(notifier);
     }
+
+    Ok(Dispatcher::new(notifiers, args.min_available))
 }
 
-fn get_available() -> Result<Vec<Location>> {
-    let body: String = ureq::get(URL).call()?.into_string()?;
+/// Repeatedly polls every provider and pushes an `Update` (the full location
+/// list plus what changed since the last poll) until the receiving end hangs up.
+fn poll_loop(providers: &[Box<dyn Provider>], session: &Session, tx: Sender<Update>) {
+    let mut current_locations: HashMap<(String, String), Location> = HashMap::new();
+    let mut rng = rand::thread_rng();
 
-    let document = Html::parse_document(&body);
+    loop {
+        let locations = match get_available(providers, session) {
+            Ok(locations) => locations,
+            Err(_) => {
+                std::thread::sleep(MIN_SLEEP_DURATION);
+                continue;
+            }
+        };
 
-    let block_selector =
-        Selector::parse(".mottagningbookabletimeslistblock .block__row.media").unwrap();
+        let _ = history::record(&locations, chrono::Local::now());
 
-    let locations = document
-        .select(&block_selector)
-        .flat_map(get_available_location)
-        .collect();
+        let mut changed = vec![];
 
-    Ok(locations)
-}
+        for location in locations.iter() {
+            current_locations
+                .entry(location.key())
+                .and_modify(|mut old_location| {
+                    if old_location.num_available != location.num_available {
+                        changed.push(location.clone());
+                    }
+                    old_location.num_available = location.num_available;
+                })
+                .or_insert_with(|| {
+                    changed.push(location.clone());
+                    location.clone()
+                });
+        }
+
+        changed.sort_by_key(|location| location.num_available);
 
-fn get_available_location<'r>(block: scraper::ElementRef<'r>) -> Option<Location> {
-    lazy_static::lazy_static! {
-        static ref LOCATION_SELECTOR: Selector = Selector::parse("h3").unwrap();
-        static ref LINK_SELECTOR: Selector = Selector::parse("a").unwrap();
-        static ref INFO_SELECTOR: Selector = Selector::parse("span").unwrap();
+        if tx
+            .send(Update {
+                locations: locations.clone(),
+                changed,
+            })
+            .is_err()
+        {
+            return;
+        }
 
-        static ref LOCATION_RE: Regex = Regex::new(r"^\s*(?P<municipality>[^:]+):\s+(?P<organization>.+)$").unwrap();
-        static ref INFO_RE: Regex = Regex::new(r"^\s*\((?P<num_available>\d+)").unwrap();
+        let sleep_duration = rng.gen_range(MIN_SLEEP_DURATION..MAX_SLEEP_DURATION);
+        std::thread::sleep(sleep_duration);
     }
+}
 
-    let location = block
-        .select(&LOCATION_SELECTOR)
-        .next()
-        .map(|location| location.text().collect::<String>());
-
-    let link = block
-        .select(&LINK_SELECTOR)
-        .next()
-        .and_then(|link| link.value().attr("href"));
-
-    let info = block
-        .select(&INFO_SELECTOR)
-        .next()
-        .map(|info| info.text().collect::<String>());
-
-    match (location, link, info) {
-        (Some(location), Some(link), Some(info)) => {
-            let location_captures = LOCATION_RE.captures(&location);
-            let info_captures = INFO_RE.captures(&info);
-
-            match (location_captures, info_captures) {
-                (Some(location_captures), Some(info_captures)) => {
-                    let municipality = location_captures
-                        .name("municipality")
-                        .unwrap()
-                        .as_str()
-                        .to_owned();
-
-                    let organization = location_captures
-                        .name("organization")
-                        .unwrap()
-                        .as_str()
-                        .to_owned();
-
-                    let num_available = info_captures
-                        .name("num_available")
-                        .unwrap()
-                        .as_str()
-                        .parse()
-                        .unwrap();
-
-                    Some(Location {
-                        municipality,
-                        organization,
-                        booking_link: link.to_owned(),
-                        num_available,
-                    })
-                }
-                _ => None,
-            }
-        }
-        _ => None,
+fn get_available(providers: &[Box<dyn Provider>], session: &Session) -> Result<Vec<Location>> {
+    let mut locations = vec![];
+
+    for provider in providers {
+        locations.extend(provider.get_available(session)?);
     }
+
+    Ok(locations)
 }
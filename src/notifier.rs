@@ -0,0 +1,167 @@
+use crate::location::Location;
+use anyhow::Result;
+use std::collections::{HashMap, HashSet};
+
+/// A way of telling the user a location changed. Implementations are
+/// stackable — a dispatch fans a location out to every configured notifier.
+pub trait Notifier {
+    fn notify(&self, location: &Location) -> Result<()>;
+}
+
+/// Opens the location's booking link in the default browser.
+pub struct BrowserNotifier;
+
+impl Notifier for BrowserNotifier {
+    fn notify(&self, location: &Location) -> Result<()> {
+        open::that(&location.booking_link)?;
+        Ok(())
+    }
+}
+
+/// Shows a desktop notification via the OS notification center.
+pub struct DesktopNotifier;
+
+impl Notifier for DesktopNotifier {
+    fn notify(&self, location: &Location) -> Result<()> {
+        notify_rust::Notification::new()
+            .summary(&format!(
+                "{} slots: {}",
+                location.num_available, location.organization
+            ))
+            .body(&location.booking_link)
+            .show()?;
+        Ok(())
+    }
+}
+
+/// POSTs the changed location as JSON to a configured webhook URL.
+pub struct WebhookNotifier {
+    url: String,
+}
+
+impl WebhookNotifier {
+    pub fn new(url: impl Into<String>) -> Self {
+        WebhookNotifier { url: url.into() }
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    fn notify(&self, location: &Location) -> Result<()> {
+        ureq::post(&self.url).send_json(location)?;
+        Ok(())
+    }
+}
+
+/// Fans changed locations out to every configured `Notifier`, applying a
+/// minimum-slots threshold and deduping per location so a count jittering
+/// between the same two values doesn't fire repeatedly. The per-location
+/// memory is cleared once a location drops below the threshold again, so a
+/// location that books out and later re-opens the same count still notifies.
+pub struct Dispatcher {
+    notifiers: Vec<Box<dyn Notifier>>,
+    min_available: u64,
+    notified: HashMap<(String, String), HashSet<u64>>,
+}
+
+impl Dispatcher {
+    pub fn new(notifiers: Vec<Box<dyn Notifier>>, min_available: u64) -> Self {
+        Dispatcher {
+            notifiers,
+            min_available,
+            notified: HashMap::new(),
+        }
+    }
+
+    pub fn dispatch(&mut self, changed: &[Location]) {
+        for location in changed {
+            if location.num_available < self.min_available {
+                self.notified.remove(&location.key());
+                continue;
+            }
+
+            let notified_values = self.notified.entry(location.key()).or_default();
+            if !notified_values.insert(location.num_available) {
+                continue;
+            }
+
+            for notifier in &self.notifiers {
+                if let Err(err) = notifier.notify(location) {
+                    eprintln!("notifier failed for {}: {}", location.organization, err);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    struct RecordingNotifier(Rc<RefCell<Vec<u64>>>);
+
+    impl Notifier for RecordingNotifier {
+        fn notify(&self, location: &Location) -> Result<()> {
+            self.0.borrow_mut().push(location.num_available);
+            Ok(())
+        }
+    }
+
+    fn location(num_available: u64) -> Location {
+        Location {
+            municipality: "Ale".to_owned(),
+            organization: "Test Clinic".to_owned(),
+            booking_link: "https://example.invalid".to_owned(),
+            num_available,
+        }
+    }
+
+    fn dispatcher(min_available: u64) -> (Dispatcher, Rc<RefCell<Vec<u64>>>) {
+        let calls = Rc::new(RefCell::new(vec![]));
+        let dispatcher = Dispatcher::new(
+            vec![Box::new(RecordingNotifier(calls.clone()))],
+            min_available,
+        );
+        (dispatcher, calls)
+    }
+
+    #[test]
+    fn skips_locations_below_the_threshold() {
+        let (mut dispatcher, calls) = dispatcher(2);
+
+        dispatcher.dispatch(&[location(1)]);
+
+        assert!(calls.borrow().is_empty());
+    }
+
+    #[test]
+    fn dedups_repeated_values_per_location() {
+        let (mut dispatcher, calls) = dispatcher(1);
+
+        dispatcher.dispatch(&[location(3)]);
+        dispatcher.dispatch(&[location(3)]);
+
+        assert_eq!(*calls.borrow(), vec![3]);
+    }
+
+    #[test]
+    fn notifies_each_distinct_value_once() {
+        let (mut dispatcher, calls) = dispatcher(1);
+
+        dispatcher.dispatch(&[location(3)]);
+        dispatcher.dispatch(&[location(4)]);
+
+        assert_eq!(*calls.borrow(), vec![3, 4]);
+    }
+
+    #[test]
+    fn re_notifies_after_dropping_below_threshold_and_back() {
+        let (mut dispatcher, calls) = dispatcher(1);
+
+        dispatcher.dispatch(&[location(3)]);
+        dispatcher.dispatch(&[location(0)]);
+        dispatcher.dispatch(&[location(3)]);
+
+        assert_eq!(*calls.borrow(), vec![3, 3]);
+    }
+}
@@ -0,0 +1,25 @@
+use crate::location::Location;
+use anyhow::Result;
+use chrono::{DateTime, Local};
+use serde::Serialize;
+
+/// One poll iteration, serialized for `--format json` consumers (`jq`,
+/// webhooks, dashboards) instead of the colored terminal table.
+#[derive(Serialize)]
+struct Record<'a> {
+    timestamp: DateTime<Local>,
+    locations: &'a [Location],
+    changed: &'a [Location],
+}
+
+pub fn print_json(locations: &[Location], changed: &[Location], timestamp: DateTime<Local>) -> Result<()> {
+    let record = Record {
+        timestamp,
+        locations,
+        changed,
+    };
+
+    println!("{}", serde_json::to_string(&record)?);
+
+    Ok(())
+}
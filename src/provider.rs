@@ -0,0 +1,39 @@
+use crate::{location::Location, session::Session};
+use anyhow::Result;
+use scraper::Html;
+
+/// A booking site extractor. Each region's scraping quirks (selectors, regexes,
+/// auth) live behind this trait so `main` can poll a list of them uniformly.
+/// `Send + Sync` so a `Vec<Box<dyn Provider>>` can be moved into the poll thread.
+pub trait Provider: Send + Sync {
+    /// The URL this provider polls for availability.
+    fn base_url(&self) -> &str;
+
+    /// Whether `url` should be handled by this provider, e.g. for a `--provider` flag.
+    /// `url` may be a short identifier rather than the full URL, so this checks
+    /// whether `base_url` contains it rather than requiring an exact prefix.
+    fn matches(&self, url: &str) -> bool {
+        self.base_url().contains(url)
+    }
+
+    /// Runs once before the poll loop starts, for providers that need to log
+    /// in or accept a cookie banner before availability is visible.
+    fn login(&self, _session: &mut Session) -> Result<()> {
+        Ok(())
+    }
+
+    /// Fetches the raw HTML for `base_url`.
+    fn fetch(&self, session: &Session) -> Result<String> {
+        session.get(self.base_url())
+    }
+
+    /// Extracts locations from a parsed document.
+    fn parse(&self, html: &Html) -> Result<Vec<Location>>;
+
+    /// Fetches and parses in one step.
+    fn get_available(&self, session: &Session) -> Result<Vec<Location>> {
+        let body = self.fetch(session)?;
+        let document = Html::parse_document(&body);
+        self.parse(&document)
+    }
+}
@@ -0,0 +1,11 @@
+mod vgregion;
+
+pub use vgregion::VgRegionProvider;
+
+use crate::provider::Provider;
+
+/// All providers this tool knows about. Adding a new region means adding a new
+/// `Provider` impl here.
+pub fn all() -> Vec<Box<dyn Provider>> {
+    vec![Box::new(VgRegionProvider)]
+}
@@ -0,0 +1,91 @@
+use crate::{location::Location, provider::Provider};
+use anyhow::Result;
+use regex::Regex;
+use scraper::{Html, Selector};
+
+const URL: &str = "https://www.vgregion.se/ov/vaccinationstider/bokningsbara-tider/";
+
+pub struct VgRegionProvider;
+
+impl Provider for VgRegionProvider {
+    fn base_url(&self) -> &str {
+        URL
+    }
+
+    fn parse(&self, document: &Html) -> Result<Vec<Location>> {
+        let block_selector =
+            Selector::parse(".mottagningbookabletimeslistblock .block__row.media").unwrap();
+
+        let locations = document
+            .select(&block_selector)
+            .flat_map(get_available_location)
+            .collect();
+
+        Ok(locations)
+    }
+}
+
+fn get_available_location<'r>(block: scraper::ElementRef<'r>) -> Option<Location> {
+    lazy_static::lazy_static! {
+        static ref LOCATION_SELECTOR: Selector = Selector::parse("h3").unwrap();
+        static ref LINK_SELECTOR: Selector = Selector::parse("a").unwrap();
+        static ref INFO_SELECTOR: Selector = Selector::parse("span").unwrap();
+
+        static ref LOCATION_RE: Regex = Regex::new(r"^\s*(?P<municipality>[^:]+):\s+(?P<organization>.+)$").unwrap();
+        static ref INFO_RE: Regex = Regex::new(r"^\s*\((?P<num_available>\d+)").unwrap();
+    }
+
+    let location = block
+        .select(&LOCATION_SELECTOR)
+        .next()
+        .map(|location| location.text().collect::<String>());
+
+    let link = block
+        .select(&LINK_SELECTOR)
+        .next()
+        .and_then(|link| link.value().attr("href"));
+
+    let info = block
+        .select(&INFO_SELECTOR)
+        .next()
+        .map(|info| info.text().collect::<String>());
+
+    match (location, link, info) {
+        (Some(location), Some(link), Some(info)) => {
+            let location_captures = LOCATION_RE.captures(&location);
+            let info_captures = INFO_RE.captures(&info);
+
+            match (location_captures, info_captures) {
+                (Some(location_captures), Some(info_captures)) => {
+                    let municipality = location_captures
+                        .name("municipality")
+                        .unwrap()
+                        .as_str()
+                        .to_owned();
+
+                    let organization = location_captures
+                        .name("organization")
+                        .unwrap()
+                        .as_str()
+                        .to_owned();
+
+                    let num_available = info_captures
+                        .name("num_available")
+                        .unwrap()
+                        .as_str()
+                        .parse()
+                        .unwrap();
+
+                    Some(Location {
+                        municipality,
+                        organization,
+                        booking_link: link.to_owned(),
+                        num_available,
+                    })
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,83 @@
+use anyhow::{anyhow, Context, Result};
+use cookie_store::CookieStoreMutex;
+use std::{
+    io::{BufReader, BufWriter},
+    path::PathBuf,
+    sync::Arc,
+};
+use ureq::{Agent, AgentBuilder};
+
+const DEFAULT_USER_AGENT: &str =
+    concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// An HTTP session shared across requests to every provider, persisting
+/// cookies across runs so portals that gate availability behind a login or a
+/// cookie banner keep working between polls.
+pub struct Session {
+    agent: Agent,
+    cookie_store: Arc<CookieStoreMutex>,
+    cookie_jar_path: PathBuf,
+}
+
+impl Session {
+    pub fn new(user_agent: Option<&str>) -> Result<Self> {
+        let cookie_jar_path = cookie_jar_path()?;
+        let cookie_store = Arc::new(CookieStoreMutex::new(load_cookie_store(&cookie_jar_path)?));
+
+        let agent = AgentBuilder::new()
+            .cookie_store_provider(cookie_store.clone())
+            .user_agent(user_agent.unwrap_or(DEFAULT_USER_AGENT))
+            .build();
+
+        Ok(Session {
+            agent,
+            cookie_store,
+            cookie_jar_path,
+        })
+    }
+
+    /// Persists the current cookie jar to disk so it survives across runs.
+    pub fn save(&self) -> Result<()> {
+        if let Some(parent) = self.cookie_jar_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let file = std::fs::File::create(&self.cookie_jar_path)?;
+        self.cookie_store
+            .lock()
+            .map_err(|_| anyhow!("cookie jar lock poisoned"))?
+            .save_json(&mut BufWriter::new(file))
+            .map_err(|err| anyhow!("failed to save cookie jar: {}", err))
+    }
+
+    /// Issues a GET and persists the cookie jar afterwards, so a session cookie
+    /// set on first visit (no explicit `login` needed) survives a restart.
+    pub fn get(&self, url: &str) -> Result<String> {
+        let body = self.agent.get(url).call()?.into_string()?;
+        self.save()?;
+        Ok(body)
+    }
+
+    /// POSTs a login form, following redirects, and returns once the session
+    /// cookie has been captured by the jar.
+    pub fn login(&self, url: &str, form: &[(&str, &str)]) -> Result<()> {
+        self.agent.post(url).send_form(form)?;
+        self.save()
+    }
+}
+
+fn cookie_jar_path() -> Result<PathBuf> {
+    let dirs = directories::ProjectDirs::from("", "", "gibvaccine")
+        .context("could not determine a config directory for this platform")?;
+    Ok(dirs.config_dir().join("cookies.json"))
+}
+
+fn load_cookie_store(path: &PathBuf) -> Result<cookie_store::CookieStore> {
+    if !path.exists() {
+        return Ok(cookie_store::CookieStore::default());
+    }
+
+    let file = std::fs::File::open(path)?;
+    cookie_store::CookieStore::load_json(BufReader::new(file))
+        .map_err(|err| anyhow!("failed to load cookie jar: {}", err))
+}
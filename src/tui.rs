@@ -0,0 +1,281 @@
+use crate::location::Location;
+use anyhow::Result;
+use std::{
+    collections::HashSet,
+    io,
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+use termion::{
+    event::Key,
+    input::TermRead,
+    raw::IntoRawMode,
+    screen::IntoAlternateScreen,
+};
+use tui::{
+    backend::TermionBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Span, Spans},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Terminal,
+};
+
+/// A poll-loop iteration, sent from the background scraping thread to the TUI
+/// or to the text/JSON output loop in `main`.
+pub struct Update {
+    pub locations: Vec<Location>,
+    pub changed: Vec<Location>,
+}
+
+enum Mode {
+    BrowseList,
+    FilterEdit,
+    Detail,
+}
+
+enum Column {
+    NumAvailable,
+    Municipality,
+    Organization,
+}
+
+struct App {
+    locations: Vec<Location>,
+    filter: String,
+    mode: Mode,
+    sort_by: Column,
+    selected: usize,
+    starred: HashSet<(String, String)>,
+}
+
+impl App {
+    fn new() -> Self {
+        App {
+            locations: vec![],
+            filter: String::new(),
+            mode: Mode::BrowseList,
+            sort_by: Column::NumAvailable,
+            selected: 0,
+            starred: HashSet::new(),
+        }
+    }
+
+    fn visible_locations(&self) -> Vec<&Location> {
+        let mut locations = self
+            .locations
+            .iter()
+            .filter(|location| {
+                self.filter.is_empty()
+                    || location
+                        .municipality
+                        .to_lowercase()
+                        .contains(&self.filter.to_lowercase())
+            })
+            .collect::<Vec<_>>();
+
+        match self.sort_by {
+            Column::NumAvailable => locations.sort_by_key(|location| location.num_available),
+            Column::Municipality => locations.sort_by(|a, b| a.municipality.cmp(&b.municipality)),
+            Column::Organization => locations.sort_by(|a, b| a.organization.cmp(&b.organization)),
+        }
+
+        locations
+    }
+
+    fn apply_update(&mut self, update: Update) {
+        self.locations = update.locations;
+
+        for location in update.changed {
+            if self.starred.contains(&location.key()) {
+                let _ = open::that(&location.booking_link);
+            }
+        }
+
+        let len = self.visible_locations().len();
+        if len == 0 {
+            self.selected = 0;
+        } else if self.selected >= len {
+            self.selected = len - 1;
+        }
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let len = self.visible_locations().len();
+        if len == 0 {
+            return;
+        }
+        let selected = self.selected as isize + delta;
+        self.selected = selected.clamp(0, len as isize - 1) as usize;
+    }
+
+    fn toggle_star(&mut self) {
+        if let Some(location) = self.visible_locations().get(self.selected) {
+            let key = location.key();
+            if !self.starred.remove(&key) {
+                self.starred.insert(key);
+            }
+        }
+    }
+}
+
+/// Runs the full-screen TUI, driven by `Update`s pushed from the poll loop.
+pub fn run(updates: Receiver<Update>) -> Result<()> {
+    let stdout = io::stdout().into_raw_mode()?.into_alternate_screen()?;
+    let backend = TermionBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.hide_cursor()?;
+
+    let keys = spawn_input_thread();
+    let mut app = App::new();
+    let mut table_state = TableState::default();
+
+    loop {
+        while let Ok(update) = updates.try_recv() {
+            app.apply_update(update);
+        }
+        table_state.select(Some(app.selected));
+
+        terminal.draw(|frame| draw(frame, &app, &mut table_state))?;
+
+        match keys.recv_timeout(Duration::from_millis(100)) {
+            Ok(key) => {
+                if !handle_key(&mut app, key) {
+                    break;
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => break,
+        }
+    }
+
+    Ok(())
+}
+
+fn spawn_input_thread() -> Receiver<Key> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let stdin = io::stdin();
+        for key in stdin.keys().flatten() {
+            if tx.send(key).is_err() {
+                break;
+            }
+        }
+    });
+    rx
+}
+
+/// Handles a key event, returning `false` if the UI should quit.
+fn handle_key(app: &mut App, key: Key) -> bool {
+    match app.mode {
+        Mode::BrowseList => match key {
+            Key::Char('q') | Key::Ctrl('c') => return false,
+            Key::Up | Key::Char('k') => app.move_selection(-1),
+            Key::Down | Key::Char('j') => app.move_selection(1),
+            Key::Char('/') => {
+                app.mode = Mode::FilterEdit;
+            }
+            Key::Char('*') => app.toggle_star(),
+            Key::Char('c') => {
+                app.sort_by = match app.sort_by {
+                    Column::NumAvailable => Column::Municipality,
+                    Column::Municipality => Column::Organization,
+                    Column::Organization => Column::NumAvailable,
+                };
+            }
+            Key::Char('\n') => {
+                if let Some(location) = app.visible_locations().get(app.selected) {
+                    let _ = open::that(&location.booking_link);
+                }
+            }
+            Key::Char('d') => {
+                if !app.visible_locations().is_empty() {
+                    app.mode = Mode::Detail;
+                }
+            }
+            _ => {}
+        },
+        Mode::FilterEdit => match key {
+            Key::Char('\n') | Key::Esc => app.mode = Mode::BrowseList,
+            Key::Backspace => {
+                app.filter.pop();
+            }
+            Key::Char(c) => app.filter.push(c),
+            _ => {}
+        },
+        Mode::Detail => match key {
+            Key::Esc | Key::Char('q') => app.mode = Mode::BrowseList,
+            Key::Char('\n') | Key::Char('o') => {
+                if let Some(location) = app.visible_locations().get(app.selected) {
+                    let _ = open::that(&location.booking_link);
+                }
+            }
+            _ => {}
+        },
+    }
+
+    true
+}
+
+fn draw<B: tui::backend::Backend>(frame: &mut tui::Frame<B>, app: &App, table_state: &mut TableState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(3)])
+        .split(frame.size());
+
+    let locations = app.visible_locations();
+
+    let rows = locations.iter().map(|location| {
+        let star = if app.starred.contains(&location.key()) {
+            "*"
+        } else {
+            " "
+        };
+        Row::new(vec![
+            Cell::from(star),
+            Cell::from(location.num_available.to_string()),
+            Cell::from(location.municipality.clone()),
+            Cell::from(location.organization.clone()),
+        ])
+    });
+
+    let table = Table::new(rows)
+        .header(
+            Row::new(vec!["", "Slots", "Municipality", "Organization"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .widths(&[
+            Constraint::Length(1),
+            Constraint::Length(6),
+            Constraint::Percentage(30),
+            Constraint::Percentage(50),
+        ])
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+        .block(Block::default().borders(Borders::ALL).title("Locations"));
+
+    frame.render_stateful_widget(table, chunks[0], table_state);
+
+    let status = match app.mode {
+        Mode::FilterEdit => Spans::from(vec![
+            Span::raw("Filter municipality: "),
+            Span::raw(app.filter.as_str()),
+        ]),
+        Mode::Detail => {
+            if let Some(location) = locations.get(app.selected) {
+                Spans::from(Span::raw(location.booking_link.clone()))
+            } else {
+                Spans::from(Span::raw(""))
+            }
+        }
+        Mode::BrowseList => Spans::from(Span::raw(
+            "↑/↓ move  Enter open  d detail  / filter  * star  c sort  q quit",
+        )),
+    };
+
+    let status_bar = Paragraph::new(status)
+        .style(Style::default().fg(Color::DarkGray))
+        .block(Block::default().borders(Borders::ALL));
+
+    frame.render_widget(status_bar, chunks[1]);
+}